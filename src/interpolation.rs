@@ -5,6 +5,11 @@
 //! **Built-in interpolations**:
 //! - [`EaseFunction`]
 //! - [`EaseClosure`]
+//! - [`CubicBezierEase`]
+//! - [`ElasticEase`]
+//! - [`BackEase`]
+//! - [`StepsEase`]
+//! - [`SampledEase`]
 //!
 //! **Systems**:
 //! - [`sample_interpolations_system`]
@@ -186,6 +191,415 @@ impl Interpolation for EaseClosure {
     }
 }
 
+/// Plugin for [`CubicBezierEase`]
+pub struct CubicBezierEasePlugin;
+impl Plugin for CubicBezierEasePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<CubicBezierEase>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<CubicBezierEase>();
+    }
+}
+
+/// CSS-style `cubic-bezier()` easing with arbitrary control points.
+///
+/// The curve's endpoints are fixed at `(0, 0)` and `(1, 1)`. `x1`/`y1` and
+/// `x2`/`y2` are the two control points in between, matching the CSS
+/// `cubic-bezier(x1, y1, x2, y2)` convention. Unlike [`EaseClosure`], this is
+/// `Reflect`/serializable since it's just four floats.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct CubicBezierEase {
+    /// X of the first control point
+    pub x1: f32,
+    /// Y of the first control point
+    pub y1: f32,
+    /// X of the second control point
+    pub x2: f32,
+    /// Y of the second control point
+    pub y2: f32,
+}
+
+impl CubicBezierEase {
+    /// Create a new [`CubicBezierEase`] from its two control points.
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        CubicBezierEase { x1, y1, x2, y2 }
+    }
+}
+
+/// Sample a cubic Bézier component with endpoints `0` and `1` and control
+/// points `a`/`b` at parameter `s`.
+fn sample_cubic_bezier(a: f32, b: f32, s: f32) -> f32 {
+    let m = 1. - s;
+    3. * m * m * s * a + 3. * m * s * s * b + s * s * s
+}
+
+/// Derivative of [`sample_cubic_bezier`] with respect to `s`.
+fn sample_cubic_bezier_derivative(a: f32, b: f32, s: f32) -> f32 {
+    let m = 1. - s;
+    3. * m * m * a + 6. * m * s * (b - a) + 3. * s * s * (1. - b)
+}
+
+impl Interpolation for CubicBezierEase {
+    fn sample(&self, v: f32) -> f32 {
+        const ITERATIONS: u32 = 8;
+
+        // Solve `bezier_x(s) = v` for `s` via Newton-Raphson, seeded at `s = v`
+        // like most CSS implementations do.
+        let mut s = v;
+        let mut degenerate = false;
+        for _ in 0..ITERATIONS {
+            let dx = sample_cubic_bezier_derivative(self.x1, self.x2, s);
+            if dx.abs() < 1e-6 {
+                degenerate = true;
+                break;
+            }
+            let x = sample_cubic_bezier(self.x1, self.x2, s) - v;
+            s -= x / dx;
+        }
+
+        if degenerate {
+            // Near-vertical segment: Newton-Raphson can diverge, fall back to
+            // bisection instead.
+            let (mut lo, mut hi) = (0., 1.);
+            for _ in 0..ITERATIONS {
+                s = (lo + hi) / 2.;
+                if sample_cubic_bezier(self.x1, self.x2, s) < v {
+                    lo = s;
+                } else {
+                    hi = s;
+                }
+            }
+        }
+
+        sample_cubic_bezier(self.y1, self.y2, s)
+    }
+}
+
+/// Direction of an easing curve, mirroring the `*In`/`*Out`/`*InOut` variants
+/// found on [`EaseFunction`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum EaseDirection {
+    /// Ease in
+    In,
+    /// Ease out
+    #[default]
+    Out,
+    /// Ease in, then out
+    InOut,
+}
+
+/// Plugin for [`ElasticEase`]
+pub struct ElasticEasePlugin;
+impl Plugin for ElasticEasePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<ElasticEase>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<ElasticEase>()
+        .register_type::<EaseDirection>();
+    }
+}
+
+/// Elastic easing with a configurable amplitude and period, unlike
+/// [`EaseFunction::ElasticIn`]/[`EaseFunction::ElasticOut`]/
+/// [`EaseFunction::ElasticInOut`] which bake in fixed constants.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct ElasticEase {
+    /// How far the curve overshoots past `0`/`1` before settling.
+    pub amplitude: f32,
+    /// Duration of one oscillation, in the same unit as the sampled input.
+    pub period: f32,
+    /// Whether to ease in, out, or both.
+    pub direction: EaseDirection,
+}
+
+impl Default for ElasticEase {
+    /// Matches the constants used by [`EaseFunction::ElasticOut`].
+    fn default() -> Self {
+        ElasticEase {
+            amplitude: 1.,
+            period: 0.3,
+            direction: EaseDirection::Out,
+        }
+    }
+}
+
+fn elastic_out(amplitude: f32, period: f32, t: f32) -> f32 {
+    if t == 0. {
+        return 0.;
+    }
+    if t == 1. {
+        return 1.;
+    }
+    let s = if amplitude >= 1. {
+        period / std::f32::consts::TAU * (1. / amplitude).asin()
+    } else {
+        period / 4.
+    };
+    amplitude * 2f32.powf(-10. * t) * ((t - s) * std::f32::consts::TAU / period).sin() + 1.
+}
+
+fn elastic_in(amplitude: f32, period: f32, t: f32) -> f32 {
+    1. - elastic_out(amplitude, period, 1. - t)
+}
+
+fn elastic_in_out(amplitude: f32, period: f32, t: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * elastic_in(amplitude, period, t * 2.)
+    } else {
+        0.5 * elastic_out(amplitude, period, t * 2. - 1.) + 0.5
+    }
+}
+
+impl Interpolation for ElasticEase {
+    fn sample(&self, v: f32) -> f32 {
+        match self.direction {
+            EaseDirection::In => elastic_in(self.amplitude, self.period, v),
+            EaseDirection::Out => elastic_out(self.amplitude, self.period, v),
+            EaseDirection::InOut => elastic_in_out(self.amplitude, self.period, v),
+        }
+    }
+}
+
+/// Plugin for [`BackEase`]
+pub struct BackEasePlugin;
+impl Plugin for BackEasePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<BackEase>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<BackEase>()
+        .register_type::<EaseDirection>();
+    }
+}
+
+/// Back easing with a configurable overshoot, unlike
+/// [`EaseFunction::BackIn`]/[`EaseFunction::BackOut`]/
+/// [`EaseFunction::BackInOut`] which bake in a fixed overshoot.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct BackEase {
+    /// How far the curve overshoots past `0`/`1` before settling.
+    pub overshoot: f32,
+    /// Whether to ease in, out, or both.
+    pub direction: EaseDirection,
+}
+
+impl Default for BackEase {
+    /// Matches the constant used by [`EaseFunction::BackOut`].
+    fn default() -> Self {
+        BackEase {
+            overshoot: 1.70158,
+            direction: EaseDirection::Out,
+        }
+    }
+}
+
+fn back_out(overshoot: f32, t: f32) -> f32 {
+    let t = t - 1.;
+    1. + (overshoot + 1.) * t * t * t + overshoot * t * t
+}
+
+fn back_in(overshoot: f32, t: f32) -> f32 {
+    1. - back_out(overshoot, 1. - t)
+}
+
+fn back_in_out(overshoot: f32, t: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * back_in(overshoot, t * 2.)
+    } else {
+        0.5 * back_out(overshoot, t * 2. - 1.) + 0.5
+    }
+}
+
+impl Interpolation for BackEase {
+    fn sample(&self, v: f32) -> f32 {
+        match self.direction {
+            EaseDirection::In => back_in(self.overshoot, v),
+            EaseDirection::Out => back_out(self.overshoot, v),
+            EaseDirection::InOut => back_in_out(self.overshoot, v),
+        }
+    }
+}
+
+/// Which side(s) of a [`StepsEase`] step produce a jump, matching the CSS
+/// `steps(n, jump)` jump terms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum JumpMode {
+    /// Jumps happen right at the start of the step, like CSS `jump-start`.
+    Start,
+    /// Jumps happen right at the end of the step, like CSS `jump-end`.
+    #[default]
+    End,
+    /// Jumps happen at both the start and the end, like CSS `jump-both`.
+    Both,
+    /// No jump at either end, like CSS `jump-none`.
+    None,
+}
+
+/// Plugin for [`StepsEase`]
+pub struct StepsEasePlugin;
+impl Plugin for StepsEasePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<StepsEase>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<StepsEase>()
+        .register_type::<JumpMode>();
+    }
+}
+
+/// Discrete, CSS `steps()`-style easing that snaps progress to one of
+/// `steps` evenly spaced plateaus instead of interpolating continuously.
+///
+/// Useful for things like driving a `TextureAtlas` index through a custom
+/// [`Interpolator`](crate::interpolate::Interpolator) and having it land on
+/// integer frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct StepsEase {
+    /// Number of discrete plateaus to divide the curve into.
+    pub steps: u32,
+    /// Which side(s) of each step produce a jump.
+    pub jump: JumpMode,
+}
+
+impl StepsEase {
+    /// Create a new [`StepsEase`] with the given step count and jump mode.
+    pub fn new(steps: u32, jump: JumpMode) -> Self {
+        StepsEase { steps, jump }
+    }
+}
+
+impl Interpolation for StepsEase {
+    fn sample(&self, v: f32) -> f32 {
+        let n = self.steps as f32;
+        match self.jump {
+            JumpMode::End => (v * n).floor() / n,
+            JumpMode::Start => (v * n).ceil() / n,
+            JumpMode::Both => (v * n + 1.).floor() / (n + 1.),
+            JumpMode::None => {
+                if self.steps == 1 {
+                    v.clamp(0., 1.)
+                } else {
+                    ((v * n).floor() / (n - 1.)).clamp(0., 1.)
+                }
+            }
+        }
+    }
+}
+
+/// Plugin for [`SampledEase`]
+pub struct SampledEasePlugin;
+impl Plugin for SampledEasePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<SampledEase>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<SampledEase>();
+    }
+}
+
+/// Bakes any [`Interpolation`] into a precomputed lookup table of evenly
+/// spaced samples.
+///
+/// Unlike [`EaseClosure`], which calls a boxed `dyn Fn` every frame and can't
+/// be reflected or serialized, or custom interpolators that re-evaluate
+/// transcendental math (such as [`ElasticEase`] or [`EaseFunction::BounceIn`])
+/// on every sample, this pays the cost once at construction time. At runtime
+/// it does a cheap, branch-free linear interpolation between the two
+/// bracketing samples, giving a uniform cost regardless of how expensive the
+/// underlying easing math is.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct SampledEase {
+    samples: Vec<f32>,
+}
+
+impl SampledEase {
+    /// Bake `n` evenly spaced samples from `interpolation` into a new
+    /// [`SampledEase`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is less than 2.
+    pub fn from_interpolation<I: Interpolation>(interpolation: I, n: usize) -> Self {
+        assert!(n >= 2, "SampledEase requires at least 2 samples");
+        let samples = (0..n)
+            .map(|i| interpolation.sample(i as f32 / (n - 1) as f32))
+            .collect();
+        SampledEase { samples }
+    }
+}
+
+impl Interpolation for SampledEase {
+    fn sample(&self, v: f32) -> f32 {
+        let last_index = self.samples.len() - 1;
+        let scaled = v.clamp(0., 1.) * last_index as f32;
+        let index = (scaled as usize).min(last_index - 1);
+        let t = scaled - index as f32;
+        self.samples[index].lerp(self.samples[index + 1], t)
+    }
+}
+
 /// This system will automatically sample in each entities with a
 /// [`TweenProgress`] component then insert [`TweenInterpolationValue`].
 /// Remove [`TweenInterpolationValue`] if [`TweenProgress`] is removed.